@@ -10,6 +10,11 @@ pub trait Epsilon {
 
     /// Return true if self is close to zero.
     fn near_zero(&self, precision: Self::Precision) -> bool;
+
+    /// Return true if self and `other` differ by no more than `rel_tol`
+    /// times the larger of the two magnitudes. Unlike `close`, this stays
+    /// meaningful for roots many orders of magnitude away from zero.
+    fn close_relative(&self, other: Self::RHS, rel_tol: Self::Precision) -> bool;
 }
 
 impl<T: Float + Signed> Epsilon for T {
@@ -23,4 +28,63 @@ impl<T: Float + Signed> Epsilon for T {
     fn near_zero(&self, precision: T) -> bool {
         abs(*self) < abs(precision)
     }
+
+    fn close_relative(&self, other: T, rel_tol: T) -> bool {
+        let largest = abs(*self).max(abs(other));
+        abs(other - *self) <= abs(rel_tol) * largest
+    }
+}
+
+/// A trait for comparing floats by their IEEE-754 bit-pattern distance,
+/// requiring concrete access to the bit representation that `Epsilon`'s
+/// generic `Float + Signed` bound can't provide.
+pub trait EpsilonUlps {
+    type RHS;
+
+    /// Return true if self and `other` are within `max_ulps` representable
+    /// floating-point steps of each other, comparing the IEEE-754 bit
+    /// patterns directly. Useful when `precision` would otherwise have to be
+    /// tuned by hand for values extremely close to zero. Always returns
+    /// `false` if either value is NaN.
+    fn close_ulps(&self, other: Self::RHS, max_ulps: u64) -> bool;
+}
+
+/// Map a sign-magnitude IEEE-754 bit pattern to an unsigned integer that
+/// sorts the same way the float it came from does, so that subtracting two
+/// of them gives the number of representable steps between the floats, even
+/// across the positive/negative zero boundary.
+macro_rules! ulp_key {
+    ($name:ident, $bits:ty, $sign_mask:expr) => {
+        fn $name(bits: $bits) -> $bits {
+            if bits & $sign_mask != 0 {
+                !bits
+            } else {
+                bits | $sign_mask
+            }
+        }
+    }
+}
+
+ulp_key!(ulp_key_32, u32, 0x8000_0000u32);
+ulp_key!(ulp_key_64, u64, 0x8000_0000_0000_0000u64);
+
+macro_rules! impl_epsilon_ulps {
+    ($float:ty, $bits:ty, $ulp_key:ident) => {
+        impl EpsilonUlps for $float {
+            type RHS = $float;
+
+            fn close_ulps(&self, other: $float, max_ulps: u64) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                let a = $ulp_key(self.to_bits());
+                let b = $ulp_key(other.to_bits());
+                let diff = if a > b { a - b } else { b - a };
+                (diff as u64) <= max_ulps
+            }
+        }
+    }
 }
+
+impl_epsilon_ulps!(f32, u32, ulp_key_32);
+impl_epsilon_ulps!(f64, u64, ulp_key_64);
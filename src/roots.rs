@@ -1,7 +1,22 @@
+use std::error;
+use std::fmt;
+
 use num::{Float, FromPrimitive, Signed, abs};
 
 use epsilon::Epsilon;
 
+/// Outcome of an internal bounded-iteration solve. `root` is `None` when the
+/// method failed outright (no bracket, a vanished derivative with no
+/// fallback, ...). When `root` is `Some`, `converged` distinguishes "met the
+/// configured precision" from "exhausted `max_iters` and returned its last
+/// estimate regardless" - the latter case is not guaranteed to be anywhere
+/// near an actual root, especially for the open methods (`secant_one`,
+/// `newton_one`, `halley_one`) whose last iterate isn't held in a bracket.
+struct Convergence<T> {
+    root: Option<T>,
+    converged: bool
+}
+
 /* ---------- bisection for intervals with a single root ---------- */
 
 /// Configuration structure for the bisection method (one root version).
@@ -24,6 +39,17 @@ pub fn bisect_one<T, F>(config: OneRootBisectCfg<T>,
     -> Option<T>
     where T: Float + FromPrimitive + Signed,
           F: Fn(T) -> T
+{
+    bisect_one_converging(config, left, right, target).root
+}
+
+fn bisect_one_converging<T, F>(config: OneRootBisectCfg<T>,
+                               left: T,
+                               right: T,
+                               target: &F)
+    -> Convergence<T>
+    where T: Float + FromPrimitive + Signed,
+          F: Fn(T) -> T
 {
     let mut iter = 0;
     let mut left = left;
@@ -32,7 +58,7 @@ pub fn bisect_one<T, F>(config: OneRootBisectCfg<T>,
     let mut right_val = target(right);
 
     if left_val * right_val > T::zero() {
-        return None;
+        return Convergence { root: None, converged: false };
     }
 
     let mut mid = (left + right) / T::from_i32(2).unwrap();
@@ -46,20 +72,22 @@ pub fn bisect_one<T, F>(config: OneRootBisectCfg<T>,
             left = mid;
             left_val = mid_val;
         } else {
-            return None;
+            return Convergence { root: None, converged: false };
         }
         iter += 1;
         mid = (left + right) / T::from_i32(2).unwrap();
         mid_val = target(mid);
     }
 
-    if abs(left_val) < abs(mid_val) {
-        Some(left)
+    let converged = right - left <= config.precision;
+    let root = if abs(left_val) < abs(mid_val) {
+        left
     } else if abs(right_val) < abs(mid_val) {
-        Some(right)
+        right
     } else {
-        Some(mid)
-    }
+        mid
+    };
+    Convergence { root: Some(root), converged }
 }
 
 /* ---------- bisection for intervals with several roots ---------- */
@@ -74,7 +102,12 @@ pub struct MultiRootBisectCfg<T> {
     pub max_iters: Option<u32>,
     /// The requested interval will be split into this many chunks, and each
     /// chunk will be tried for a root.
-    pub num_intervals: usize
+    pub num_intervals: usize,
+    /// If true, duplicate detection between adjacent chunks uses
+    /// `close_relative` instead of `close`, so `precision` means "relative
+    /// digits of agreement" rather than an absolute distance. Useful when
+    /// roots may be many orders of magnitude away from zero.
+    pub relative: bool
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -132,7 +165,14 @@ impl<'a, T, F> Iterator for MultiRootBisectState<'a, T, F>
             if let Some(root) = res {
                 let two = T::from_i32(2).unwrap();
                 let double_prec = two * self.cfg.precision;
-                let mapper = |old: T| old.close(root, double_prec);
+                let relative = self.cfg.relative;
+                let mapper = |old: T| {
+                    if relative {
+                        old.close_relative(root, double_prec)
+                    } else {
+                        old.close(root, double_prec)
+                    }
+                };
                 let duplicate = self.last_root.map_or(false, mapper);
                 if duplicate {
                     continue
@@ -145,6 +185,50 @@ impl<'a, T, F> Iterator for MultiRootBisectState<'a, T, F>
     }
 }
 
+/* ---------- automatic bracket expansion ---------- */
+
+/// Search outward from a single `guess` for a bracket `(left, right)` with
+/// `f(left) * f(right) <= 0`, for use with `bisect_one`/`brent_one` when the
+/// caller only has a rough starting point instead of a hand-tuned interval.
+///
+/// Starts with the pair `(guess, guess + initial_step)` and, as long as
+/// neither point straddles a root, keeps pushing the point whose `|f|` is
+/// smaller further away from the other by `factor` times their current
+/// distance - growing the step geometrically until a sign change is found
+/// or `max_iters` is exhausted.
+pub fn bracket_expand<T, F>(guess: T,
+                            initial_step: T,
+                            factor: T,
+                            max_iters: u32,
+                            target: &F)
+    -> Option<(T, T)>
+    where T: Float + Signed,
+          F: Fn(T) -> T
+{
+    let mut x1 = guess;
+    let mut x2 = guess + initial_step;
+    let mut f1 = target(x1);
+    let mut f2 = target(x2);
+    let mut iter = 0;
+    while f1 * f2 > T::zero() && iter < max_iters {
+        if abs(f1) < abs(f2) {
+            x1 = x1 + factor * (x1 - x2);
+            f1 = target(x1);
+        } else {
+            x2 = x2 + factor * (x2 - x1);
+            f2 = target(x2);
+        }
+        iter += 1;
+    }
+    if f1 * f2 > T::zero() {
+        None
+    } else if x1 < x2 {
+        Some((x1, x2))
+    } else {
+        Some((x2, x1))
+    }
+}
+
 /* ---------- Newton's method ---------- */
 
 /// Configuration structure for the Newton's method (one root version).
@@ -155,7 +239,12 @@ pub struct OneRootNewtonCfg<T> {
     pub precision: T,
     /// A limit on the number of iterations to perform. Pass `None` if you
     /// don't want a limit.
-    pub max_iters: Option<u32>
+    pub max_iters: Option<u32>,
+    /// If true, successive iterates are compared with `close_relative`
+    /// instead of `close`, so `precision` means "relative digits of
+    /// agreement" rather than an absolute distance. Useful when the root
+    /// may be many orders of magnitude away from zero.
+    pub relative: bool
 }
 
 pub fn newton_one<T, F, D>(config: OneRootNewtonCfg<T>,
@@ -168,31 +257,51 @@ pub fn newton_one<T, F, D>(config: OneRootNewtonCfg<T>,
     where T: Float + Epsilon<RHS=T, Precision=T>,
           F: Fn(T) -> T,
           D: Fn(T) -> T
+{
+    newton_one_converging(config, left, right, first_approx, target, derivative).root
+}
+
+fn newton_one_converging<T, F, D>(config: OneRootNewtonCfg<T>,
+                                  left: T,
+                                  right: T,
+                                  first_approx: T,
+                                  target: &F,
+                                  derivative: &D)
+    -> Convergence<T>
+    where T: Float + Epsilon<RHS=T, Precision=T>,
+          F: Fn(T) -> T,
+          D: Fn(T) -> T
 {
     let mut left = left;
     let mut right = right;
     let mut root = first_approx;
     let mut prev_root = None;
     let mut iter = 0;
-    while prev_root.map_or(true, |old| !root.close(old, config.precision))
-        && config.max_iters.map_or(true, |max| iter < max) {
+    let mut converged = false;
+    while prev_root.map_or(true, |old| {
+        if config.relative {
+            !root.close_relative(old, config.precision)
+        } else {
+            !root.close(old, config.precision)
+        }
+    }) && config.max_iters.map_or(true, |max| iter < max) {
             iter += 1;
             let left_val = target(left);
             let right_val = target(right);
             if let Some(next) = next_newton_iter(config.precision,
-                                                 left, 
-                                                 right, 
-                                                 root, 
-                                                 target, 
+                                                 left,
+                                                 right,
+                                                 root,
+                                                 target,
                                                  derivative) {
                 prev_root = Some(root);
                 root = next;
-            } else if let Some(fallback_root) 
+            } else if let Some(fallback_root)
                 = linear_fallback(left, right, left_val, right_val) {
                     prev_root = Some(root);
                     root = fallback_root;
             } else {
-                return None
+                return Convergence { root: None, converged: false }
             }
             let val_at_root = target(root);
             if left_val * val_at_root <= T::zero() {
@@ -200,8 +309,13 @@ pub fn newton_one<T, F, D>(config: OneRootNewtonCfg<T>,
             } else {
                 left = root;
             }
+            converged = if config.relative {
+                root.close_relative(prev_root.unwrap(), config.precision)
+            } else {
+                root.close(prev_root.unwrap(), config.precision)
+            };
     }
-    Some(root)
+    Convergence { root: Some(root), converged }
 }
 
 fn next_newton_iter<T, F, D>(prec: T,
@@ -241,6 +355,598 @@ fn linear_fallback<T: Float>(x1: T , x2: T, y1: T, y2: T) -> Option<T>
     }
 }
 
+/* ---------- Brent's method ---------- */
+
+/// Configuration structure for Brent's method.
+#[derive(Debug, Clone, Copy)]
+pub struct OneRootBrentCfg<T> {
+    /// The real root, if any, will be no further than this from the reported
+    /// root.
+    pub precision: T,
+    /// A limit on the number of iterations to perform. Pass `None` if you
+    /// don't want a limit.
+    pub max_iters: Option<u32>
+}
+
+/// Find a root for a given function in a given interval using Brent's
+/// method, which combines bisection, the secant method and inverse
+/// quadratic interpolation to get superlinear convergence while always
+/// keeping hold of a valid sign-changing bracket.
+pub fn brent_one<T, F>(config: OneRootBrentCfg<T>,
+                       left: T,
+                       right: T,
+                       target: &F)
+    -> Option<T>
+    where T: Float + FromPrimitive + Signed,
+          F: Fn(T) -> T
+{
+    brent_one_converging(config, left, right, target).root
+}
+
+fn brent_one_converging<T, F>(config: OneRootBrentCfg<T>,
+                              left: T,
+                              right: T,
+                              target: &F)
+    -> Convergence<T>
+    where T: Float + FromPrimitive + Signed,
+          F: Fn(T) -> T
+{
+    let two = T::from_i32(2).unwrap();
+    let three = T::from_i32(3).unwrap();
+    let four = T::from_i32(4).unwrap();
+
+    let mut a = left;
+    let mut b = right;
+    let mut fa = target(a);
+    let mut fb = target(b);
+
+    if fa * fb > T::zero() {
+        return Convergence { root: None, converged: false };
+    }
+
+    if abs(fa) < abs(fb) {
+        let (na, nb) = (b, a);
+        let (nfa, nfb) = (fb, fa);
+        a = na; b = nb;
+        fa = nfa; fb = nfb;
+    }
+
+    let mut c = a;
+    let mut fc = fa;
+    let mut d = a;
+    let mut mflag = true;
+    let max = config.max_iters;
+    let mut iter = 0;
+
+    while fb != T::zero()
+        && abs(b - a) > config.precision
+        && max.map_or(true, |m| iter < m) {
+            let s = if fa != fc && fb != fc {
+                a * fb * fc / ((fa - fb) * (fa - fc))
+                    + b * fa * fc / ((fb - fa) * (fb - fc))
+                    + c * fa * fb / ((fc - fa) * (fc - fb))
+            } else {
+                b - fb * (b - a) / (fb - fa)
+            };
+
+            let in_interp_window = if a < b {
+                s > (three * a + b) / four && s < b
+            } else {
+                s < (three * a + b) / four && s > b
+            };
+            let bisect = !in_interp_window
+                || (mflag && abs(s - b) >= abs(b - c) / two)
+                || (!mflag && abs(s - b) >= abs(c - d) / two)
+                || (mflag && abs(b - c) <= config.precision)
+                || (!mflag && abs(c - d) <= config.precision);
+
+            let s = if bisect {
+                mflag = true;
+                (a + b) / two
+            } else {
+                mflag = false;
+                s
+            };
+
+            let fs = target(s);
+            d = c;
+            c = b;
+            fc = fb;
+
+            if fa * fs < T::zero() {
+                b = s;
+                fb = fs;
+            } else {
+                a = s;
+                fa = fs;
+            }
+
+            if abs(fa) < abs(fb) {
+                let (na, nb) = (b, a);
+                let (nfa, nfb) = (fb, fa);
+                a = na; b = nb;
+                fa = nfa; fb = nfb;
+            }
+
+            iter += 1;
+    }
+
+    let converged = fb == T::zero() || abs(b - a) <= config.precision;
+    Convergence { root: Some(b), converged }
+}
+
+/* ---------- Halley's method ---------- */
+
+/// Find a root for a given function using Halley's method, which converges
+/// cubically when a cheap second derivative is available. Reuses
+/// `OneRootNewtonCfg` and the same bracket-clamping behaviour as
+/// `newton_one`.
+pub fn halley_one<T, F, D1, D2>(config: OneRootNewtonCfg<T>,
+                                left: T,
+                                right: T,
+                                first_approx: T,
+                                target: &F,
+                                first_deriv: &D1,
+                                second_deriv: &D2)
+    -> Option<T>
+    where T: Float + FromPrimitive + Epsilon<RHS=T, Precision=T>,
+          F: Fn(T) -> T,
+          D1: Fn(T) -> T,
+          D2: Fn(T) -> T
+{
+    halley_one_converging(config, left, right, first_approx, target,
+                          first_deriv, second_deriv).root
+}
+
+fn halley_one_converging<T, F, D1, D2>(config: OneRootNewtonCfg<T>,
+                                       left: T,
+                                       right: T,
+                                       first_approx: T,
+                                       target: &F,
+                                       first_deriv: &D1,
+                                       second_deriv: &D2)
+    -> Convergence<T>
+    where T: Float + FromPrimitive + Epsilon<RHS=T, Precision=T>,
+          F: Fn(T) -> T,
+          D1: Fn(T) -> T,
+          D2: Fn(T) -> T
+{
+    let mut left = left;
+    let mut right = right;
+    let mut root = first_approx;
+    let mut prev_root = None;
+    let mut iter = 0;
+    let mut converged = false;
+    while prev_root.map_or(true, |old| {
+        if config.relative {
+            !root.close_relative(old, config.precision)
+        } else {
+            !root.close(old, config.precision)
+        }
+    }) && config.max_iters.map_or(true, |max| iter < max) {
+            iter += 1;
+            let left_val = target(left);
+            let right_val = target(right);
+            if let Some(next) = next_halley_iter(config.precision,
+                                                 left,
+                                                 right,
+                                                 root,
+                                                 target,
+                                                 first_deriv,
+                                                 second_deriv) {
+                prev_root = Some(root);
+                root = next;
+            } else if let Some(fallback_root)
+                = linear_fallback(left, right, left_val, right_val) {
+                    prev_root = Some(root);
+                    root = fallback_root;
+            } else {
+                return Convergence { root: None, converged: false }
+            }
+            let val_at_root = target(root);
+            if left_val * val_at_root <= T::zero() {
+                right = root;
+            } else {
+                left = root;
+            }
+            converged = if config.relative {
+                root.close_relative(prev_root.unwrap(), config.precision)
+            } else {
+                root.close(prev_root.unwrap(), config.precision)
+            };
+    }
+    Convergence { root: Some(root), converged }
+}
+
+fn next_halley_iter<T, F, D1, D2>(prec: T,
+                                  left: T,
+                                  right: T,
+                                  old: T,
+                                  target: &F,
+                                  first_deriv: &D1,
+                                  second_deriv: &D2)
+    -> Option<T>
+    where T: Float + FromPrimitive + Epsilon<RHS=T, Precision=T>,
+          F: Fn(T) -> T,
+          D1: Fn(T) -> T,
+          D2: Fn(T) -> T
+{
+    let f = target(old);
+    let d1 = first_deriv(old);
+    let d2 = second_deriv(old);
+    let two = T::from_i32(2).unwrap();
+    let denom = two * d1 * d1 - f * d2;
+    if denom.near_zero(prec) {
+        return None
+    }
+    let res = old - (two * f * d1) / denom;
+    if res < left {
+        None
+    } else if res > right {
+        None
+    } else {
+        Some(res)
+    }
+}
+
+/* ---------- secant method ---------- */
+
+/// Configuration structure for the secant method.
+#[derive(Debug, Clone, Copy)]
+pub struct OneRootSecantCfg<T> {
+    /// The real root, if any, is most likely to be within this distance from
+    /// the reported root, but this is not guaranteed.
+    pub precision: T,
+    /// A limit on the number of iterations to perform. Pass `None` if you
+    /// don't want a limit.
+    pub max_iters: Option<u32>
+}
+
+/// Find a root for a given function using the secant method, seeded with two
+/// initial guesses `x0` and `x1`. Unlike `newton_one`, this does not require
+/// an analytic derivative, approximating it from the two most recent
+/// iterates instead.
+pub fn secant_one<T, F>(config: OneRootSecantCfg<T>,
+                        x0: T,
+                        x1: T,
+                        target: &F)
+    -> Option<T>
+    where T: Float + Epsilon<RHS=T, Precision=T>,
+          F: Fn(T) -> T
+{
+    secant_one_converging(config, x0, x1, target).root
+}
+
+fn secant_one_converging<T, F>(config: OneRootSecantCfg<T>,
+                               x0: T,
+                               x1: T,
+                               target: &F)
+    -> Convergence<T>
+    where T: Float + Epsilon<RHS=T, Precision=T>,
+          F: Fn(T) -> T
+{
+    let mut prev = x0;
+    let mut cur = x1;
+    let mut prev_val = target(prev);
+    let mut cur_val = target(cur);
+    let mut iter = 0;
+    while config.max_iters.map_or(true, |max| iter < max) {
+        let denom = cur_val - prev_val;
+        if denom.near_zero(config.precision) {
+            return Convergence { root: None, converged: false };
+        }
+        let next = cur - cur_val * (cur - prev) / denom;
+        if cur.close(next, config.precision) {
+            return Convergence { root: Some(next), converged: true };
+        }
+        prev = cur;
+        prev_val = cur_val;
+        cur = next;
+        cur_val = target(cur);
+        iter += 1;
+    }
+    Convergence { root: Some(cur), converged: false }
+}
+
+/* ---------- false position (regula falsi) ---------- */
+
+/// Find a root for a given function in a given interval using the false
+/// position method: like `bisect_one`, but each step narrows the bracket
+/// using `linear_fallback`'s secant-style estimate instead of the midpoint.
+/// When the same end of the bracket is kept on two steps in a row, the
+/// function value stored for that end is halved (the Illinois variant), so
+/// both ends keep moving and the bracket shrinks below `precision`. Stops
+/// early if an estimate hits an exact zero.
+pub fn false_position_one<T, F>(config: OneRootBisectCfg<T>,
+                                left: T,
+                                right: T,
+                                target: &F)
+    -> Option<T>
+    where T: Float + FromPrimitive + Signed,
+          F: Fn(T) -> T
+{
+    false_position_one_converging(config, left, right, target).root
+}
+
+fn false_position_one_converging<T, F>(config: OneRootBisectCfg<T>,
+                                       left: T,
+                                       right: T,
+                                       target: &F)
+    -> Convergence<T>
+    where T: Float + FromPrimitive + Signed,
+          F: Fn(T) -> T
+{
+    let mut left = left;
+    let mut right = right;
+    let mut left_val = target(left);
+    let mut right_val = target(right);
+
+    if left_val * right_val > T::zero() {
+        return Convergence { root: None, converged: false };
+    }
+
+    let two = T::from_i32(2).unwrap();
+    let mut iter = 0;
+    let max = config.max_iters;
+    // Which end the last step kept: -1 for `left`, 1 for `right`, 0 at first.
+    let mut kept = 0;
+    let mut x = match linear_fallback(left, right, left_val, right_val) {
+        Some(x) => x,
+        None => return Convergence { root: None, converged: false }
+    };
+    let mut x_val = target(x);
+    while x_val != T::zero()
+        && right - left > config.precision
+        && max.map_or(true, |m| iter < m) {
+        if left_val * x_val <= T::zero() {
+            right = x;
+            right_val = x_val;
+            if kept < 0 {
+                left_val = left_val / two;
+            }
+            kept = -1;
+        } else {
+            left = x;
+            left_val = x_val;
+            if kept > 0 {
+                right_val = right_val / two;
+            }
+            kept = 1;
+        }
+        iter += 1;
+        x = match linear_fallback(left, right, left_val, right_val) {
+            Some(x) => x,
+            None => return Convergence { root: None, converged: false }
+        };
+        x_val = target(x);
+    }
+    let converged = x_val == T::zero() || right - left <= config.precision;
+    Convergence { root: Some(x), converged }
+}
+
+/* ---------- unified root finder builder ---------- */
+
+/// Which algorithm a `RootFinder` should use to look for a root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Bisection,
+    FalsePosition,
+    Secant,
+    Newton,
+    Halley,
+    Brent
+}
+
+/// Why a `RootFinder` failed to produce a root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootFindError {
+    /// The chosen method needs a bracket, but none was supplied via
+    /// `RootFinder::bracket`.
+    NoBracket,
+    /// The supplied bracket does not straddle a root
+    /// (`f(left) * f(right) > 0`).
+    NoSignChange,
+    /// The chosen method needs a derivative, but none was supplied via
+    /// `RootFinder::derivative`/`RootFinder::second_derivative`.
+    MissingDerivative,
+    /// An open method (`Secant`, `Newton`, `Halley`) could not take a step.
+    /// For `Secant`, the difference `f(x_n) - f(x_{n-1})` vanished. For
+    /// `Newton` and `Halley`, either the derivative (for `Halley`,
+    /// `2 f'^2 - f f''`) vanished or the step left the bracket, and the
+    /// linear fallback between the bracket ends failed too. This includes
+    /// a bracket whose ends have the same sign.
+    DerivativeVanished,
+    /// The solver exhausted `max_iters` without meeting `precision` (the
+    /// bracket never shrank below it, or successive iterates never settled
+    /// down to within it). The estimate at that point is discarded rather
+    /// than reported, since for the open methods (`Secant`, `Newton`,
+    /// `Halley`) it isn't held in a bracket and may be far from any root.
+    MaxIterationsReached
+}
+
+impl fmt::Display for RootFindError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match *self {
+            RootFindError::NoBracket => "no bracket was supplied",
+            RootFindError::NoSignChange => "the bracket does not straddle a root",
+            RootFindError::MissingDerivative => "the method requires a derivative that was not supplied",
+            RootFindError::DerivativeVanished => "a derivative vanished during iteration",
+            RootFindError::MaxIterationsReached => "the iteration limit was reached before converging to the requested precision"
+        };
+        f.write_str(msg)
+    }
+}
+
+impl error::Error for RootFindError {}
+
+/// A builder over all of the root-finding algorithms in this module, so
+/// callers can switch strategies without rewriting call sites:
+///
+/// ```ignore
+/// let root = RootFinder::new(&target)
+///     .bracket(left, right)
+///     .method(Method::Secant)
+///     .precision(1e-9)
+///     .max_iters(Some(100))
+///     .solve();
+/// ```
+pub struct RootFinder<'a, T, F: 'a> {
+    target: &'a F,
+    bracket: Option<(T, T)>,
+    first_approx: Option<T>,
+    method: Method,
+    precision: T,
+    max_iters: Option<u32>,
+    relative: bool,
+    derivative: Option<&'a Fn(T) -> T>,
+    second_derivative: Option<&'a Fn(T) -> T>
+}
+
+impl<'a, T, F> RootFinder<'a, T, F>
+    where T: Float + FromPrimitive + Signed + Epsilon<RHS=T, Precision=T>,
+          F: Fn(T) -> T
+{
+    /// Start building a solver for `target`. Defaults to `Bisection` with a
+    /// precision of `1e-6` and no iteration limit.
+    pub fn new(target: &'a F) -> Self {
+        RootFinder {
+            target,
+            bracket: None,
+            first_approx: None,
+            method: Method::Bisection,
+            precision: T::from_f64(1e-6).unwrap(),
+            max_iters: None,
+            relative: false,
+            derivative: None,
+            second_derivative: None
+        }
+    }
+
+    /// Set the bracket `[left, right]` required by every method. `Secant`
+    /// also reads this pair, but treats it as its two seed points `x0`,
+    /// `x1` rather than a sign-changing interval.
+    pub fn bracket(mut self, left: T, right: T) -> Self {
+        self.bracket = Some((left, right));
+        self
+    }
+
+    /// Set the initial approximation used by `Newton` and `Halley`. Defaults
+    /// to the midpoint of the bracket if not supplied.
+    pub fn first_approx(mut self, x: T) -> Self {
+        self.first_approx = Some(x);
+        self
+    }
+
+    /// Pick which algorithm `solve` should dispatch to.
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn precision(mut self, precision: T) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn max_iters(mut self, max_iters: Option<u32>) -> Self {
+        self.max_iters = max_iters;
+        self
+    }
+
+    /// Use `close_relative` rather than `close` for the convergence checks
+    /// of `Newton` and `Halley`, so `precision` is meaningful regardless of
+    /// the root's order of magnitude.
+    pub fn relative(mut self, relative: bool) -> Self {
+        self.relative = relative;
+        self
+    }
+
+    /// Supply the first derivative, required by `Newton` and `Halley`.
+    pub fn derivative(mut self, d: &'a Fn(T) -> T) -> Self {
+        self.derivative = Some(d);
+        self
+    }
+
+    /// Supply the second derivative, required by `Halley`.
+    pub fn second_derivative(mut self, d: &'a Fn(T) -> T) -> Self {
+        self.second_derivative = Some(d);
+        self
+    }
+
+    /// Dispatch to the configured algorithm and look for a root.
+    pub fn solve(self) -> Result<T, RootFindError> {
+        let outright_failure_error = match self.method {
+            Method::Bisection | Method::FalsePosition | Method::Brent => RootFindError::NoSignChange,
+            Method::Secant | Method::Newton | Method::Halley => RootFindError::DerivativeVanished
+        };
+        let convergence = match self.method {
+            Method::Bisection => {
+                let (left, right) = self.bracket.ok_or(RootFindError::NoBracket)?;
+                let cfg = OneRootBisectCfg {
+                    precision: self.precision,
+                    max_iters: self.max_iters
+                };
+                bisect_one_converging(cfg, left, right, self.target)
+            },
+            Method::FalsePosition => {
+                let (left, right) = self.bracket.ok_or(RootFindError::NoBracket)?;
+                let cfg = OneRootBisectCfg {
+                    precision: self.precision,
+                    max_iters: self.max_iters
+                };
+                false_position_one_converging(cfg, left, right, self.target)
+            },
+            Method::Brent => {
+                let (left, right) = self.bracket.ok_or(RootFindError::NoBracket)?;
+                let cfg = OneRootBrentCfg {
+                    precision: self.precision,
+                    max_iters: self.max_iters
+                };
+                brent_one_converging(cfg, left, right, self.target)
+            },
+            Method::Secant => {
+                let (x0, x1) = self.bracket.ok_or(RootFindError::NoBracket)?;
+                let cfg = OneRootSecantCfg {
+                    precision: self.precision,
+                    max_iters: self.max_iters
+                };
+                secant_one_converging(cfg, x0, x1, self.target)
+            },
+            Method::Newton => {
+                let (left, right) = self.bracket.ok_or(RootFindError::NoBracket)?;
+                let derivative = self.derivative.ok_or(RootFindError::MissingDerivative)?;
+                let two = T::from_i32(2).unwrap();
+                let first_approx = self.first_approx.unwrap_or((left + right) / two);
+                let cfg = OneRootNewtonCfg {
+                    precision: self.precision,
+                    max_iters: self.max_iters,
+                    relative: self.relative
+                };
+                newton_one_converging(cfg, left, right, first_approx, self.target, derivative)
+            },
+            Method::Halley => {
+                let (left, right) = self.bracket.ok_or(RootFindError::NoBracket)?;
+                let first_deriv = self.derivative.ok_or(RootFindError::MissingDerivative)?;
+                let second_deriv = self.second_derivative.ok_or(RootFindError::MissingDerivative)?;
+                let two = T::from_i32(2).unwrap();
+                let first_approx = self.first_approx.unwrap_or((left + right) / two);
+                let cfg = OneRootNewtonCfg {
+                    precision: self.precision,
+                    max_iters: self.max_iters,
+                    relative: self.relative
+                };
+                halley_one_converging(cfg, left, right, first_approx, self.target,
+                                      first_deriv, second_deriv)
+            }
+        };
+        match convergence {
+            Convergence { root: None, .. } => Err(outright_failure_error),
+            Convergence { root: Some(_), converged: false } => Err(RootFindError::MaxIterationsReached),
+            Convergence { root: Some(root), converged: true } => Ok(root)
+        }
+    }
+}
+
 /* ---------- unit tests ---------- */
 
 #[cfg(test)]
@@ -252,112 +958,367 @@ mod tests {
 
         use galvanic_assert::matchers::*;
 
-        use epsilon::Epsilon;
+        use epsilon::{Epsilon, EpsilonUlps};
         use roots::*;
 
-        test bisect_one_pos_1() {
-            let target = |x| x;
+        test secant_one_pos_1() {
+            let target = |x: f64| (x - 1.0) * (x - 2.0) * (x - 3.0);
             let prec = 1e-6;
-            let cfg = OneRootBisectCfg { precision: prec, max_iters: None };
-            let root = bisect_one(cfg, -1.0, 1.0, &target);
-            assert_that!(root.unwrap().close(0.0, prec));
+            let cfg = OneRootSecantCfg {
+                precision: prec,
+                max_iters: None
+            };
+            let root = secant_one(cfg, 0.5, 0.6, &target);
+            assert_that!(root.unwrap().close(1.0, prec));
+            let root = secant_one(cfg, 2.5, 2.6, &target);
+            assert_that!(root.unwrap().close(3.0, prec));
+        }
+
+        test secant_one_pos_2() {
+            let target = |x: f64| x.pow(0.1) - 1.0;
+            let prec = 1e-6;
+            let cfg = OneRootSecantCfg {
+                precision: prec,
+                max_iters: None
+            };
+            let root = secant_one(cfg, 0.5, 0.6, &target);
+            assert_that!(root.unwrap().close(1.0, prec));
         }
 
-        test bisect_one_pos_2() {
+        test secant_one_neg_1() {
+            let target = |_: f64| 5.0;
+            let prec = 1e-6;
+            let cfg = OneRootSecantCfg {
+                precision: prec,
+                max_iters: Some(50)
+            };
+            let root = secant_one(cfg, 0.0, 1.0, &target);
+            assert_that!(root.is_none());
+        }
+
+        test false_position_one_pos_1() {
             let target = |x| (x - 2.0) * (x + 2.0);
-            let prec = 1e-9;
+            let prec = 1e-6;
             let cfg = OneRootBisectCfg { precision: prec, max_iters: None };
-            let root1 = bisect_one(cfg, 1.8, 2.1, &target);
-            let root2 = bisect_one(cfg, -10.0, 0.0, &target);
+            let root1 = false_position_one(cfg, 1.8, 2.1, &target);
+            let root2 = false_position_one(cfg, -10.0, 0.0, &target);
             assert_that!(root1.unwrap().close(2.0, prec));
             assert_that!(root2.unwrap().close(-2.0, prec));
         }
 
-        test bisect_one_neg_1() {
+        test false_position_one_neg_1() {
             let target = |x| x;
             let prec = 1e-6;
             let cfg = OneRootBisectCfg { precision: prec, max_iters: None };
-            let root = bisect_one(cfg, 1.0, 2.0, &target);
+            let root = false_position_one(cfg, 1.0, 2.0, &target);
             assert_that!(&root, is_variant!(None));
         }
 
-        test bisect_multi_pos_1() {
-            let target = |x| (x - 2.0) * (x + 2.0);
-            let prec = 1e-6;
-            let cfg = MultiRootBisectCfg {
-                precision: prec,
-                max_iters: None,
-                num_intervals: 20
-            };
-            let roots: Vec<_> = bisect_multi(cfg, -3.0, 3.0, &target).collect();
-            assert_that!(&roots.len(), eq(2));
-            assert_that!(roots[0].close(-2.0, prec));
-            assert_that!(roots[1].close(2.0, prec));
-        }
+        test root_finder_pos_1() {
+            let target = |x: f64| (x - 2.0) * (x + 2.0);
+            let der = |x: f64| 2.0 * x;
+            let prec = 1e-9;
 
-        test bisect_multi_pos_2() {
-            let target = |x| x;
-            let prec = 1e-6;
-            let cfg = MultiRootBisectCfg {
-                precision: prec,
-                max_iters: None,
-                num_intervals: 2
-            };
-            let roots: Vec<_> = bisect_multi(cfg, -1.0, 1.0, &target).collect();
-            assert_that!(&roots.len(), eq(1));
-            assert_that!(roots[0].close(0.0, prec));
+            let root = RootFinder::new(&target)
+                .bracket(1.8, 2.1)
+                .method(Method::Bisection)
+                .precision(prec)
+                .solve();
+            assert_that!(root.unwrap().close(2.0, prec));
+
+            let root = RootFinder::new(&target)
+                .bracket(1.8, 2.1)
+                .method(Method::Brent)
+                .precision(prec)
+                .solve();
+            assert_that!(root.unwrap().close(2.0, prec));
+
+            let root = RootFinder::new(&target)
+                .bracket(1.5, 2.5)
+                .method(Method::Newton)
+                .precision(prec)
+                .derivative(&der)
+                .solve();
+            assert_that!(root.unwrap().close(2.0, prec));
         }
 
-        test bisect_multi_neg_1() {
-            let target = |x| (x - 1.0) * (x - 2.0);
-            let prec = 1e-6;
-            let cfg = MultiRootBisectCfg {
-                precision: prec,
-                max_iters: None,
-                num_intervals: 10
-            };
-            let roots = bisect_multi(cfg, 3.0, 4.0, &target).collect::<Vec<_>>();
-            assert_that!(&roots, eq(vec![]));
+        test root_finder_neg_1() {
+            let target = |x: f64| (x - 2.0) * (x + 2.0);
+
+            let err = RootFinder::new(&target)
+                .method(Method::Bisection)
+                .solve();
+            assert_that!(&err, is_variant!(Err));
+
+            let err = RootFinder::new(&target)
+                .bracket(1.5, 2.5)
+                .method(Method::Newton)
+                .solve();
+            assert_that!(&err, eq(Err(RootFindError::MissingDerivative)));
         }
 
-        test newton_one_pos_1() {
+        test halley_one_pos_1() {
             let target = |x: f64| (x - 1.0) * (x - 2.0) * (x - 3.0);
             let der = |x: f64| 3.0 * x.pow(2) - 12.0 * x + 11.0;
+            let der2 = |x: f64| 6.0 * x - 12.0;
             let prec = 1e-6;
             let cfg = OneRootNewtonCfg {
                 precision: prec,
-                max_iters: None
+                max_iters: None,
+                relative: false
             };
-            let root = newton_one(cfg, 0.5, 1.5, 0.55, &target, &der);
+            let root = halley_one(cfg, 0.5, 1.5, 0.55, &target, &der, &der2);
             assert_that!(root.unwrap().close(1.0, prec));
-            let root = newton_one(cfg, 1.5, 2.5, 1.55, &target, &der);
-            assert_that!(root.unwrap().close(2.0, prec));
-            let root = newton_one(cfg, 2.5, 4.0, 3.15, &target, &der);
+            let root = halley_one(cfg, 2.5, 4.0, 3.15, &target, &der, &der2);
             assert_that!(root.unwrap().close(3.0, prec));
         }
 
-        test newton_one_pos_2() {
-            let target = |x: f64| x.pow(0.1) - 1.0;
-            let der = |x: f64| 0.1 * x.pow(-0.9);
+        test halley_one_neg_1() {
+            let target = |x: f64| (x - 1.0) * (x - 2.0) * (x - 3.0);
+            let der = |x: f64| 3.0 * x.pow(2) - 12.0 * x + 11.0;
+            let der2 = |x: f64| 6.0 * x - 12.0;
             let prec = 1e-6;
             let cfg = OneRootNewtonCfg {
                 precision: prec,
-                max_iters: None
+                max_iters: None,
+                relative: false
             };
-            let root = newton_one(cfg, 0.5, 1.5, 0.55, &target, &der);
-            assert_that!(root.unwrap().close(1.0, prec));
+            let root = halley_one(cfg, 5.0, 6.0, 5.5, &target, &der, &der2);
+            assert_that!(root.is_none());
         }
 
-        test newton_one_neg_1() {
+        test brent_one_pos_1() {
+            let target = |x| (x - 2.0) * (x + 2.0);
+            let prec = 1e-9;
+            let cfg = OneRootBrentCfg { precision: prec, max_iters: None };
+            let root1 = brent_one(cfg, 1.8, 2.1, &target);
+            let root2 = brent_one(cfg, -10.0, 0.0, &target);
+            assert_that!(root1.unwrap().close(2.0, prec));
+            assert_that!(root2.unwrap().close(-2.0, prec));
+        }
+
+        test brent_one_pos_2() {
             let target = |x: f64| (x - 1.0) * (x - 2.0) * (x - 3.0);
-            let der = |x: f64| 3.0 * x.pow(2) - 12.0 * x + 11.0;
+            let prec = 1e-9;
+            let cfg = OneRootBrentCfg { precision: prec, max_iters: None };
+            let root = brent_one(cfg, 0.5, 1.5, &target);
+            assert_that!(root.unwrap().close(1.0, prec));
+        }
+
+        test brent_one_neg_1() {
+            let target = |x| x;
             let prec = 1e-6;
+            let cfg = OneRootBrentCfg { precision: prec, max_iters: None };
+            let root = brent_one(cfg, 1.0, 2.0, &target);
+            assert_that!(&root, is_variant!(None));
+        }
+
+        test bracket_expand_pos_1() {
+            let target = |x: f64| (x - 100.0) * (x + 5.0);
+            let bracket = bracket_expand(0.0, 1.0, 1.5, 100, &target);
+            let (left, right) = bracket.unwrap();
+            assert_that!(left < right);
+            assert_that!(target(left) * target(right) <= 0.0);
+        }
+
+        test bracket_expand_neg_1() {
+            let target = |x: f64| x * x + 1.0;
+            let bracket = bracket_expand(0.0, 1.0, 1.5, 20, &target);
+            assert_that!(&bracket, is_variant!(None));
+        }
+
+        test close_relative_pos_1() {
+            assert_that!(1.0e10_f64.close_relative(1.0e10 + 1.0, 1e-9));
+            assert_that!(!1.0e10_f64.close_relative(1.1e10, 1e-9));
+        }
+
+        test close_ulps_pos_1() {
+            assert_that!(1.0_f64.close_ulps(1.0 + f64::EPSILON, 1));
+            assert_that!(!1.0_f64.close_ulps(1.1, 1));
+            assert_that!(!1.0_f64.close_ulps(f64::NAN, 1));
+        }
+
+        test newton_one_relative_pos_1() {
+            let target = |x: f64| (x - 1.0e8) * (x + 1.0e8);
+            let der = |x: f64| 2.0 * x;
+            let prec = 1e-9;
             let cfg = OneRootNewtonCfg {
                 precision: prec,
-                max_iters: None
+                max_iters: None,
+                relative: true
             };
-            let root = newton_one(cfg, 5.0, 6.0, 5.5, &target, &der);
-            assert_that!(root.is_none());
+            let root = newton_one(cfg, 0.0, 2.0e8, 1.5e8, &target, &der);
+            assert_that!(root.unwrap().close_relative(1.0e8, prec));
+        }
+    }
+
+    mod proptest_tests {
+        use proptest::prelude::*;
+
+        use epsilon::Epsilon;
+        use roots::*;
+
+        /// Build `f(x) = product(x - r)` and its analytic derivative for a
+        /// set of (not necessarily distinct) roots.
+        fn poly_and_deriv(roots: Vec<f64>) -> (impl Fn(f64) -> f64, impl Fn(f64) -> f64) {
+            let f_roots = roots.clone();
+            let f = move |x: f64| f_roots.iter().fold(1.0, |acc, &r| acc * (x - r));
+            let d_roots = roots;
+            let d = move |x: f64| {
+                (0..d_roots.len()).fold(0.0, |acc, i| {
+                    let term = d_roots.iter().enumerate()
+                        .filter(|&(j, _)| j != i)
+                        .fold(1.0, |p, (_, &r)| p * (x - r));
+                    acc + term
+                })
+            };
+            (f, d)
+        }
+
+        proptest! {
+            #[test]
+            fn bisect_one_finds_root(r in -1.0e3f64..1.0e3, offset in 1e-2f64..10.0) {
+                let (f, _) = poly_and_deriv(vec![r]);
+                let prec = 1e-6;
+                let cfg = OneRootBisectCfg { precision: prec, max_iters: Some(10_000) };
+                let root = bisect_one(cfg, r - offset, r + offset, &f);
+                prop_assert!(root.is_some());
+                prop_assert!(root.unwrap().close(r, prec * 10.0));
+            }
+
+            #[test]
+            fn newton_one_finds_root(r in -1.0e3f64..1.0e3, offset in 1e-2f64..5.0) {
+                let (f, d) = poly_and_deriv(vec![r]);
+                let prec = 1e-6;
+                let cfg = OneRootNewtonCfg {
+                    precision: prec,
+                    max_iters: Some(10_000),
+                    relative: false
+                };
+                let left = r - offset;
+                let right = r + offset;
+                let first_approx = (left + right) / 2.0;
+                let root = newton_one(cfg, left, right, first_approx, &f, &d);
+                prop_assert!(root.is_some());
+                prop_assert!(root.unwrap().close(r, prec * 10.0));
+            }
+
+            #[test]
+            fn secant_one_finds_root(r in -1.0e3f64..1.0e3, offset in 1e-2f64..5.0) {
+                let (f, _) = poly_and_deriv(vec![r]);
+                let prec = 1e-6;
+                let cfg = OneRootSecantCfg { precision: prec, max_iters: Some(10_000) };
+                let root = secant_one(cfg, r - offset, r + offset, &f);
+                prop_assert!(root.is_some());
+                prop_assert!(root.unwrap().close(r, prec * 10.0));
+            }
+
+            #[test]
+            fn halley_one_finds_root(r in -1.0e3f64..1.0e3, offset in 1e-2f64..5.0) {
+                let roots = vec![r];
+                let second_roots = roots.clone();
+                let (f, d) = poly_and_deriv(roots);
+                let d2 = move |x: f64| {
+                    let n = second_roots.len();
+                    (0..n).fold(0.0, |acc, i| {
+                        (0..n).filter(|&j| j != i).fold(acc, |acc2, j| {
+                            let term = (0..n).filter(|&k| k != i && k != j)
+                                .fold(1.0, |p, k| p * (x - second_roots[k]));
+                            acc2 + term
+                        })
+                    })
+                };
+                let prec = 1e-6;
+                let cfg = OneRootNewtonCfg {
+                    precision: prec,
+                    max_iters: Some(10_000),
+                    relative: false
+                };
+                let left = r - offset;
+                let right = r + offset;
+                let first_approx = (left + right) / 2.0;
+                let root = halley_one(cfg, left, right, first_approx, &f, &d, &d2);
+                prop_assert!(root.is_some());
+                prop_assert!(root.unwrap().close(r, prec * 10.0));
+            }
+
+            #[test]
+            fn brent_one_finds_root(r in -1.0e3f64..1.0e3, offset in 1e-2f64..10.0) {
+                let (f, _) = poly_and_deriv(vec![r]);
+                let prec = 1e-9;
+                let cfg = OneRootBrentCfg { precision: prec, max_iters: Some(10_000) };
+                let root = brent_one(cfg, r - offset, r + offset, &f);
+                prop_assert!(root.is_some());
+                prop_assert!(root.unwrap().close(r, prec * 10.0));
+            }
+
+            #[test]
+            fn bisect_multi_counts_distinct_roots(
+                mut rs in prop::collection::vec(-50.0f64..50.0, 1..6)
+            ) {
+                rs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                rs.dedup_by(|a, b| (*a - *b).abs() < 1.0);
+                let (f, _) = poly_and_deriv(rs.clone());
+                let prec = 1e-6;
+                let cfg = MultiRootBisectCfg {
+                    precision: prec,
+                    max_iters: Some(10_000),
+                    num_intervals: 2000,
+                    relative: false
+                };
+                let found: Vec<_> = bisect_multi(cfg, -60.0, 60.0, &f).collect();
+                prop_assert_eq!(found.len(), rs.len());
+            }
+
+            #[test]
+            fn newton_one_guards_flat_regions(x in -10.0f64..10.0) {
+                // A perfectly flat function has a vanishing derivative
+                // everywhere, so `next_newton_iter`'s guard rejects every
+                // step and `linear_fallback` can't rescue it either (it
+                // divides by the same zero): there is no root to find, and
+                // `newton_one` must report that rather than looping forever
+                // or returning a bogus estimate.
+                let target = |_: f64| 1.0;
+                let der = |_: f64| 0.0;
+                let prec = 1e-6;
+                let cfg = OneRootNewtonCfg {
+                    precision: prec,
+                    max_iters: Some(50),
+                    relative: false
+                };
+                let root = newton_one(cfg, x - 1.0, x + 1.0, x, &target, &der);
+                prop_assert!(root.is_none());
+            }
+
+            #[test]
+            fn newton_one_handles_near_tangent_double_root(r in -1.0e2f64..1.0e2) {
+                // A double root touches zero without changing sign, so the
+                // first iterate (r + 0.25) has the sign of `f(left)` and
+                // becomes the new `left`. The next Newton step (r + 0.125)
+                // falls below it and is rejected, and `linear_fallback`
+                // between two same-signed ends lands outside the bracket
+                // too, so `newton_one` gives up instead of looping.
+                let target = move |x: f64| (x - r) * (x - r);
+                let der = move |x: f64| 2.0 * (x - r);
+                let prec = 1e-4;
+                let cfg = OneRootNewtonCfg {
+                    precision: prec,
+                    max_iters: Some(10_000),
+                    relative: false
+                };
+                let root = newton_one(cfg, r - 1.0, r + 1.0, r + 0.5, &target, &der);
+                prop_assert!(root.is_none());
+            }
+
+            #[test]
+            fn bisect_one_handles_root_near_endpoint(r in -1.0e2f64..1.0e2) {
+                let (f, _) = poly_and_deriv(vec![r]);
+                let prec = 1e-6;
+                let cfg = OneRootBisectCfg { precision: prec, max_iters: Some(10_000) };
+                let root = bisect_one(cfg, r - 1e-3, r + 1.0, &f);
+                prop_assert!(root.is_some());
+                prop_assert!(root.unwrap().close(r, prec * 10.0));
+            }
         }
     }
 }